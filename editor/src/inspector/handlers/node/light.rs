@@ -0,0 +1,36 @@
+use crate::{
+    make_command, scene::commands::script::SetScriptPropertyCommand,
+    scene::shadow::LightShadowSettings, SceneCommand,
+};
+use fyrox::{
+    core::pool::Handle,
+    gui::inspector::{FieldKind, PropertyChanged},
+    scene::node::Node,
+};
+
+/// [`LightShadowSettings`] is attached to a light as a script (it implements `ScriptTrait`), so its
+/// fields flow through the same generic [`SetScriptPropertyCommand`] as other script properties -
+/// unlike node-intrinsic properties (e.g. [`crate::inspector::handlers::node::rectangle::handle_rectangle_property_changed`]),
+/// there's no node state to branch on here, just the field name.
+pub fn handle_light_shadow_property_changed(
+    args: &PropertyChanged,
+    handle: Handle<Node>,
+) -> Option<SceneCommand> {
+    if let FieldKind::Object(ref value) = args.value {
+        match args.name.as_ref() {
+            LightShadowSettings::FILTER
+            | LightShadowSettings::DEPTH_BIAS
+            | LightShadowSettings::SAMPLE_COUNT
+            | LightShadowSettings::LIGHT_SIZE => {
+                make_command!(
+                    SetScriptPropertyCommand::<LightShadowSettings, _>,
+                    handle,
+                    value
+                )
+            }
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{
+    make_command, scene::commands::script::SetScriptPropertyCommand,
+    scene::trigger_volume::LevelTriggerVolume, SceneCommand,
+};
+use fyrox::{
+    core::pool::Handle,
+    gui::inspector::{FieldKind, PropertyChanged},
+    scene::node::Node,
+};
+
+/// [`LevelTriggerVolume`] is attached as a script (see its module docs), so its `target_scene`/
+/// `mode` fields flow through the same generic [`SetScriptPropertyCommand`] as other script
+/// properties - see [`crate::inspector::handlers::node::light::handle_light_shadow_property_changed`]
+/// for the same pattern applied to another script.
+pub fn handle_trigger_volume_property_changed(
+    args: &PropertyChanged,
+    handle: Handle<Node>,
+) -> Option<SceneCommand> {
+    if let FieldKind::Object(ref value) = args.value {
+        match args.name.as_ref() {
+            LevelTriggerVolume::TARGET_SCENE => {
+                make_command!(SetScriptPropertyCommand::<LevelTriggerVolume, _>, handle, value)
+            }
+            LevelTriggerVolume::MODE => {
+                make_command!(SetScriptPropertyCommand::<LevelTriggerVolume, _>, handle, value)
+            }
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
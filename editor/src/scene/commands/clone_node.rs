@@ -0,0 +1,240 @@
+use crate::{
+    scene::Selection, world::graph::selection::GraphSelection, Command, SceneContext,
+};
+use fyrox::{
+    core::{pool::Handle, reflect::Reflect},
+    scene::{graph::Graph, node::Node},
+};
+use std::collections::HashMap;
+
+/// Deep-copies a node - and, recursively, everything parented under it - into a brand new,
+/// fully independent node. The destination node is created fresh via the node constructor
+/// container (keyed by the source's type UUID) and then populated field-by-field through
+/// reflection, rather than going through the type's own hand-written `Clone`/`clone_box` impl -
+/// that's what lets fields the editor doesn't explicitly know about still come along for the
+/// ride. This is what "Duplicate" runs under the hood; see [`DuplicateSelectionCommand`] for how
+/// the UI action wires it up to the current selection.
+#[derive(Debug)]
+pub struct CloneNodeCommand {
+    node: Handle<Node>,
+    parent: Handle<Node>,
+    clone: Handle<Node>,
+}
+
+impl CloneNodeCommand {
+    pub fn new(node: Handle<Node>) -> Self {
+        Self {
+            node,
+            parent: Handle::NONE,
+            clone: Handle::NONE,
+        }
+    }
+
+    /// The clone produced the last time this command was executed.
+    pub fn clone_handle(&self) -> Handle<Node> {
+        self.clone
+    }
+}
+
+impl Command for CloneNodeCommand {
+    fn name(&mut self, _: &SceneContext) -> String {
+        "Clone Node".to_owned()
+    }
+
+    fn execute(&mut self, context: &mut SceneContext) {
+        let graph = &mut context.scene.graph;
+
+        self.parent = graph[self.node].parent();
+
+        let (clone, _) = clone_subgraph(graph, self.node);
+        self.clone = clone;
+
+        graph.link_nodes(self.clone, self.parent);
+    }
+
+    fn revert(&mut self, context: &mut SceneContext) {
+        context.scene.graph.remove_node(self.clone);
+    }
+}
+
+/// Runs [`CloneNodeCommand`] once per node in the current selection as a single undo step, then
+/// selects the newly produced clones - the "Duplicate" UI action is this command.
+#[derive(Debug)]
+pub struct DuplicateSelectionCommand {
+    clones: Vec<CloneNodeCommand>,
+    previous_selection: Selection,
+}
+
+impl DuplicateSelectionCommand {
+    pub fn new(selection: &GraphSelection) -> Self {
+        Self {
+            clones: selection
+                .nodes()
+                .iter()
+                .map(|&handle| CloneNodeCommand::new(handle))
+                .collect(),
+            previous_selection: Selection::Graph(selection.clone()),
+        }
+    }
+}
+
+impl Command for DuplicateSelectionCommand {
+    fn name(&mut self, _: &SceneContext) -> String {
+        "Duplicate Selection".to_owned()
+    }
+
+    fn execute(&mut self, context: &mut SceneContext) {
+        let mut new_selection = Vec::with_capacity(self.clones.len());
+
+        for clone_command in &mut self.clones {
+            clone_command.execute(context);
+            new_selection.push(clone_command.clone_handle());
+        }
+
+        *context.selection = Selection::Graph(GraphSelection::from_list(new_selection));
+    }
+
+    fn revert(&mut self, context: &mut SceneContext) {
+        for clone_command in self.clones.iter_mut().rev() {
+            clone_command.revert(context);
+        }
+
+        *context.selection = self.previous_selection.clone();
+    }
+}
+
+/// Recursively clones `root` and all of its descendants, then remaps any `Handle<Node>` fields
+/// found along the way (e.g. bone references) so the clone points at its own hierarchy instead
+/// of the original's.
+fn clone_subgraph(
+    graph: &mut Graph,
+    root: Handle<Node>,
+) -> (Handle<Node>, HashMap<Handle<Node>, Handle<Node>>) {
+    let mut old_to_new = HashMap::new();
+    let clone_root = clone_node_reflected(graph, root, &mut old_to_new);
+    remap_internal_handles(graph, &old_to_new);
+    (clone_root, old_to_new)
+}
+
+fn clone_node_reflected(
+    graph: &mut Graph,
+    handle: Handle<Node>,
+    old_to_new: &mut HashMap<Handle<Node>, Handle<Node>>,
+) -> Handle<Node> {
+    let source = &graph[handle];
+    let type_uuid = source.id();
+
+    // Create a blank node of the same concrete type via the registered constructor rather than
+    // `clone_box` - that per-type `Clone` impl is exactly the "hand-written per-type copy" this
+    // command exists to avoid. Everything the constructor doesn't already carry over as a sane
+    // default is then filled in field-by-field below.
+    let mut destination = graph
+        .constructor_container()
+        .try_create(&type_uuid)
+        .unwrap_or_else(|| panic!("node type {type_uuid} has no registered constructor"));
+
+    copy_reflected_fields(source.as_reflect(), destination.as_reflect_mut());
+
+    let children = graph[handle].children().to_vec();
+    let new_handle = graph.add_node(destination);
+    old_to_new.insert(handle, new_handle);
+
+    for child in children {
+        let new_child = clone_node_reflected(graph, child, old_to_new);
+        graph.link_nodes(new_child, new_handle);
+    }
+
+    new_handle
+}
+
+/// Copies every reflected field from `source` to `destination`, recursing into nested
+/// `Inspectable`-style fields (e.g. `Base`). `parent`/`children` are skipped deliberately - the
+/// caller rebuilds hierarchy itself as it recurses through the source's children, and copying
+/// those handles verbatim would leave the clone pointing at the *original's* relatives.
+fn copy_reflected_fields(source: &dyn Reflect, destination: &mut dyn Reflect) {
+    source.fields_ref(&mut |fields| {
+        for field in fields {
+            if field.name() == "parent" || field.name() == "children" {
+                continue;
+            }
+
+            if let Some(target) = destination.field_mut(field.name()) {
+                if target.set(field.value().clone_value()).is_err() {
+                    if let (Some(nested_source), Some(nested_destination)) =
+                        (field.as_reflect(), target.as_reflect_mut())
+                    {
+                        copy_reflected_fields(nested_source, nested_destination);
+                    }
+                }
+            }
+        }
+    });
+}
+
+fn remap_internal_handles(graph: &mut Graph, old_to_new: &HashMap<Handle<Node>, Handle<Node>>) {
+    for &new_handle in old_to_new.values() {
+        remap_handles_in_reflect(graph[new_handle].as_reflect_mut(), old_to_new);
+    }
+}
+
+/// Recursively walks every reflected field of `reflect`, remapping any `Handle<Node>` found -
+/// directly, nested inside an `Inspectable` sub-struct, or inside a reflected collection (e.g.
+/// `Surface::bones: Vec<Handle<Node>>`) - from the original hierarchy to the freshly cloned one.
+/// Mirrors `copy_reflected_fields`'s recursion for the same reason: a plain, one-level
+/// `fields_mut_ref` pass would miss exactly the nested/collection cases that matter most (a
+/// skinned mesh's bone references live inside `Vec<Surface>`, not directly on the node).
+fn remap_handles_in_reflect(
+    reflect: &mut dyn Reflect,
+    old_to_new: &HashMap<Handle<Node>, Handle<Node>>,
+) {
+    if let Some(handle) = reflect.downcast_mut::<Handle<Node>>() {
+        if let Some(remapped) = old_to_new.get(handle) {
+            *handle = *remapped;
+        }
+        return;
+    }
+
+    if let Some(list) = reflect.as_list_mut() {
+        for index in 0..list.reflect_len() {
+            if let Some(item) = list.reflect_index_mut(index) {
+                remap_handles_in_reflect(item, old_to_new);
+            }
+        }
+        return;
+    }
+
+    reflect.fields_mut_ref(&mut |fields| {
+        for field in fields {
+            remap_handles_in_reflect(field.as_reflect_mut(), old_to_new);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fyrox::scene::{base::BaseBuilder, pivot::PivotBuilder};
+
+    #[test]
+    fn clone_subgraph_creates_independent_hierarchy() {
+        let mut graph = Graph::new();
+
+        let child = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+        let root = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+        graph.link_nodes(child, root);
+
+        let (clone_root, old_to_new) = clone_subgraph(&mut graph, root);
+
+        assert_ne!(clone_root, root);
+        assert_eq!(old_to_new.len(), 2);
+        assert_eq!(old_to_new[&root], clone_root);
+
+        let clone_children = graph[clone_root].children().to_vec();
+        assert_eq!(clone_children.len(), 1);
+        assert_ne!(clone_children[0], child);
+        assert_eq!(old_to_new[&child], clone_children[0]);
+
+        // Cloning must leave the original hierarchy untouched.
+        assert_eq!(graph[root].children(), &[child]);
+    }
+}
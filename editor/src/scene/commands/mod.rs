@@ -0,0 +1,13 @@
+//! Undoable scene edits. Every user-facing edit to a loaded scene goes through a `Command`
+//! pushed onto the editor's command stack, so it can be undone/redone uniformly.
+//!
+//! `Command`, `SceneContext`, and the crate-root `SceneCommand` enum that dispatches to every
+//! editor feature's commands (`Rectangle`, `Script`, ...) already exist upstream of this module -
+//! this file only adds the new `clone_node` submodule. `CloneNodeCommand`/`DuplicateSelectionCommand`
+//! plug into `SceneCommand` as two more variants (`CloneNode`, `DuplicateSelection`) alongside the
+//! existing ones, the same way every other command submodule here does; they are not a
+//! replacement for it.
+
+pub mod clone_node;
+
+pub use clone_node::{CloneNodeCommand, DuplicateSelectionCommand};
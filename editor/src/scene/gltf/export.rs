@@ -0,0 +1,404 @@
+use super::FYROX_NODE_EXTRAS_KEY;
+use fyrox::{
+    asset::ResourceKind,
+    core::pool::Handle,
+    resource::texture::Texture,
+    scene::{graph::Graph, mesh::Mesh, node::Node, Scene},
+};
+use gltf_json as json;
+use json::validation::Checked;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Tracks which backing file each already-exported texture came from, so surfaces that share a
+/// texture resource (a common case - many surfaces reuse the same diffuse map) emit one `image`/
+/// `texture` pair instead of a duplicate per surface.
+type TextureCache = HashMap<PathBuf, json::Index<json::texture::Texture>>;
+
+#[derive(Debug)]
+pub enum GltfExportError {
+    Io(io::Error),
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for GltfExportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GltfExportError::Io(e) => write!(f, "I/O error while writing glTF file: {}", e),
+            GltfExportError::Json(e) => write!(f, "Failed to serialize glTF document: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for GltfExportError {}
+
+impl From<io::Error> for GltfExportError {
+    fn from(e: io::Error) -> Self {
+        GltfExportError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for GltfExportError {
+    fn from(e: serde_json::Error) -> Self {
+        GltfExportError::Json(e)
+    }
+}
+
+/// Walks `scene.graph` from the children of its root and emits a `.gltf` document (with an
+/// accompanying `.bin` buffer) at `path`. The graph's own root node is not exported: the importer
+/// places top-level nodes under a fresh scene's root, so exporting it too would nest the scene one
+/// level deeper on every import/export cycle. Each node's name and Fyrox handle are preserved in
+/// glTF `extras` so that re-importing the result lines back up with the original scene - see
+/// `import_node` in `import.rs`, which reads the same `FYROX_NODE_EXTRAS_KEY` entry back out.
+pub fn export_gltf(scene: &Scene, path: &Path) -> Result<(), GltfExportError> {
+    let mut root = json::Root::default();
+    let mut buffer_data = Vec::new();
+    let mut textures = TextureCache::new();
+
+    let scene_nodes = scene.graph[scene.graph.get_root()]
+        .children()
+        .iter()
+        .map(|&handle| {
+            export_node(&scene.graph, handle, &mut root, &mut buffer_data, &mut textures)
+        })
+        .collect::<Vec<_>>();
+
+    root.scenes.push(json::Scene {
+        extensions: Default::default(),
+        extras: Default::default(),
+        name: None,
+        nodes: scene_nodes,
+    });
+    root.scene = Some(json::Index::new(0));
+
+    let bin_path = path.with_extension("bin");
+    fs::write(&bin_path, &buffer_data)?;
+
+    root.buffers.push(json::Buffer {
+        byte_length: buffer_data.len() as u32,
+        extensions: Default::default(),
+        extras: Default::default(),
+        name: None,
+        uri: bin_path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned()),
+    });
+
+    let json_string = serde_json::to_string_pretty(&root)?;
+    fs::write(path, json_string)?;
+
+    Ok(())
+}
+
+fn export_node(
+    graph: &Graph,
+    handle: Handle<Node>,
+    root: &mut json::Root,
+    buffer_data: &mut Vec<u8>,
+    textures: &mut TextureCache,
+) -> json::Index<json::Node> {
+    let node = &graph[handle];
+
+    let translation = node.local_transform().position();
+    let rotation = node.local_transform().rotation();
+    let scale = node.local_transform().scale();
+
+    let mesh_index = node
+        .cast::<Mesh>()
+        .map(|mesh| export_mesh(mesh, root, buffer_data, textures));
+
+    let children = node
+        .children()
+        .iter()
+        .map(|child| export_node(graph, *child, root, buffer_data, textures))
+        .collect();
+
+    // Reuse the tag a prior import stashed here (see `import.rs::read_fyrox_extras_tag`) so a
+    // round-tripped node keeps the same extras value across import/export cycles; only nodes
+    // that never went through our importer get a fresh one derived from their handle.
+    let tag = if node.tag().is_empty() {
+        format!("{}:{}", handle.index(), handle.generation())
+    } else {
+        node.tag().to_owned()
+    };
+
+    let mut extras = serde_json::Map::new();
+    extras.insert(FYROX_NODE_EXTRAS_KEY.to_owned(), serde_json::Value::String(tag));
+
+    let index = json::Index::new(root.nodes.len() as u32);
+    root.nodes.push(json::Node {
+        camera: None,
+        children: Some(children),
+        extensions: Default::default(),
+        extras: Some(serde_json::Value::Object(extras).into()),
+        matrix: None,
+        mesh: mesh_index,
+        name: Some(node.name().to_owned()),
+        rotation: Some(json::scene::UnitQuaternion([
+            rotation.i, rotation.j, rotation.k, rotation.w,
+        ])),
+        scale: Some([scale.x, scale.y, scale.z]),
+        translation: Some([translation.x, translation.y, translation.z]),
+        skin: None,
+        weights: None,
+    });
+
+    index
+}
+
+/// Bytes of a single interleaved vertex, matching `fyrox::scene::mesh::vertex::StaticVertex`:
+/// position (vec3), normal (vec3), tex coord (vec2).
+const VERTEX_STRIDE: u32 = 12 + 12 + 8;
+
+fn export_mesh(
+    mesh: &Mesh,
+    root: &mut json::Root,
+    buffer_data: &mut Vec<u8>,
+    textures: &mut TextureCache,
+) -> json::Index<json::Mesh> {
+    let mut primitives = Vec::new();
+
+    for surface in mesh.surfaces() {
+        let shared_data = surface.data();
+        let data = shared_data.data_ref();
+        let geometry = data.geometry_buffer();
+        let triangles = data.triangles();
+
+        let vertex_count = geometry.len() as u32;
+        let vertex_byte_offset = buffer_data.len() as u32;
+        for vertex in geometry.iter() {
+            buffer_data.extend_from_slice(bytemuck::bytes_of(vertex));
+        }
+
+        let vertex_view = push_buffer_view(
+            root,
+            vertex_byte_offset,
+            vertex_count * VERTEX_STRIDE,
+            Some(VERTEX_STRIDE),
+            json::buffer::Target::ArrayBuffer,
+        );
+
+        let position_accessor = push_accessor(
+            root,
+            vertex_view,
+            0,
+            vertex_count,
+            json::accessor::Type::Vec3,
+        );
+        let normal_accessor = push_accessor(
+            root,
+            vertex_view,
+            12,
+            vertex_count,
+            json::accessor::Type::Vec3,
+        );
+        let tex_coord_accessor = push_accessor(
+            root,
+            vertex_view,
+            24,
+            vertex_count,
+            json::accessor::Type::Vec2,
+        );
+
+        let index_count = (triangles.len() * 3) as u32;
+        let index_byte_offset = buffer_data.len() as u32;
+        for triangle in triangles.iter() {
+            for index in triangle.0 {
+                buffer_data.extend_from_slice(&index.to_le_bytes());
+            }
+        }
+
+        let index_view = push_buffer_view(
+            root,
+            index_byte_offset,
+            index_count * 4,
+            None,
+            json::buffer::Target::ElementArrayBuffer,
+        );
+        let index_accessor = push_index_accessor(root, index_view, index_count);
+
+        let mut attributes = BTreeMap::new();
+        attributes.insert(Checked::Valid(json::mesh::Semantic::Positions), position_accessor);
+        attributes.insert(Checked::Valid(json::mesh::Semantic::Normals), normal_accessor);
+        attributes.insert(
+            Checked::Valid(json::mesh::Semantic::TexCoords(0)),
+            tex_coord_accessor,
+        );
+
+        let material = surface
+            .material()
+            .data_ref()
+            .texture("diffuseTexture")
+            .map(|texture| export_material(root, &texture, textures));
+
+        primitives.push(json::mesh::Primitive {
+            attributes,
+            extensions: Default::default(),
+            extras: Default::default(),
+            indices: Some(index_accessor),
+            material,
+            mode: Checked::Valid(json::mesh::Mode::Triangles),
+            targets: None,
+        });
+    }
+
+    let index = json::Index::new(root.meshes.len() as u32);
+    root.meshes.push(json::Mesh {
+        extensions: Default::default(),
+        extras: Default::default(),
+        name: None,
+        primitives,
+        weights: None,
+    });
+
+    index
+}
+
+fn push_buffer_view(
+    root: &mut json::Root,
+    byte_offset: u32,
+    byte_length: u32,
+    byte_stride: Option<u32>,
+    target: json::buffer::Target,
+) -> json::Index<json::buffer::View> {
+    let index = json::Index::new(root.buffer_views.len() as u32);
+    root.buffer_views.push(json::buffer::View {
+        buffer: json::Index::new(0),
+        byte_length,
+        byte_offset: Some(byte_offset),
+        byte_stride: byte_stride.map(json::buffer::Stride),
+        extensions: Default::default(),
+        extras: Default::default(),
+        name: None,
+        target: Some(Checked::Valid(target)),
+    });
+    index
+}
+
+fn push_accessor(
+    root: &mut json::Root,
+    buffer_view: json::Index<json::buffer::View>,
+    byte_offset: u32,
+    count: u32,
+    accessor_type: json::accessor::Type,
+) -> json::Index<json::Accessor> {
+    let index = json::Index::new(root.accessors.len() as u32);
+    root.accessors.push(json::Accessor {
+        buffer_view: Some(buffer_view),
+        byte_offset: Some(byte_offset),
+        count,
+        component_type: Checked::Valid(json::accessor::GenericComponentType(
+            json::accessor::ComponentType::F32,
+        )),
+        extensions: Default::default(),
+        extras: Default::default(),
+        type_: Checked::Valid(accessor_type),
+        min: None,
+        max: None,
+        name: None,
+        normalized: false,
+        sparse: None,
+    });
+    index
+}
+
+fn push_index_accessor(
+    root: &mut json::Root,
+    buffer_view: json::Index<json::buffer::View>,
+    count: u32,
+) -> json::Index<json::Accessor> {
+    let index = json::Index::new(root.accessors.len() as u32);
+    root.accessors.push(json::Accessor {
+        buffer_view: Some(buffer_view),
+        byte_offset: Some(0),
+        count,
+        component_type: Checked::Valid(json::accessor::GenericComponentType(
+            json::accessor::ComponentType::U32,
+        )),
+        extensions: Default::default(),
+        extras: Default::default(),
+        type_: Checked::Valid(json::accessor::Type::Scalar),
+        min: None,
+        max: None,
+        name: None,
+        normalized: false,
+        sparse: None,
+    });
+    index
+}
+
+/// Pushes a bare PBR metallic-roughness material referencing `texture` as its base color map via
+/// [`push_texture`]. The texture's backing file is expected to already have been written
+/// alongside the project's asset folder by whatever exported the surface's resource in the first
+/// place; only the reference is recorded here.
+fn export_material(
+    root: &mut json::Root,
+    texture: &Texture,
+    textures: &mut TextureCache,
+) -> json::Index<json::Material> {
+    let texture_index = push_texture(root, texture, textures);
+
+    let index = json::Index::new(root.materials.len() as u32);
+    root.materials.push(json::Material {
+        pbr_metallic_roughness: json::material::PbrMetallicRoughness {
+            base_color_texture: Some(json::texture::Info {
+                index: texture_index,
+                tex_coord: 0,
+                extensions: Default::default(),
+                extras: Default::default(),
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    index
+}
+
+/// Pushes the `image`/`texture` pair a material's `base_color_texture` points at, reusing the
+/// existing entry if `texture`'s backing file was already referenced by an earlier surface. No
+/// pixel data is embedded - the `image`'s `uri` is just the backing file's name, matching
+/// `export_material`'s assumption that the file itself travels alongside the exported document.
+fn push_texture(
+    root: &mut json::Root,
+    texture: &Texture,
+    textures: &mut TextureCache,
+) -> json::Index<json::texture::Texture> {
+    let path = match texture.kind() {
+        ResourceKind::External(path) => path,
+        ResourceKind::Embedded => PathBuf::from(format!("embedded_texture_{}.png", textures.len())),
+    };
+
+    if let Some(&index) = textures.get(&path) {
+        return index;
+    }
+
+    let uri = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.to_string_lossy().into_owned());
+
+    let image_index = json::Index::new(root.images.len() as u32);
+    root.images.push(json::image::Image {
+        buffer_view: None,
+        mime_type: None,
+        name: None,
+        uri: Some(uri),
+        extensions: Default::default(),
+        extras: Default::default(),
+    });
+
+    let texture_index = json::Index::new(root.textures.len() as u32);
+    root.textures.push(json::texture::Texture {
+        name: None,
+        sampler: None,
+        source: image_index,
+        extensions: Default::default(),
+        extras: Default::default(),
+    });
+
+    textures.insert(path, texture_index);
+    texture_index
+}
@@ -0,0 +1,223 @@
+use crate::scene::gltf::FYROX_NODE_EXTRAS_KEY;
+use fyrox::{
+    asset::manager::ResourceManager,
+    core::{
+        algebra::{Matrix4, UnitQuaternion, Vector2, Vector3},
+        pool::Handle,
+    },
+    resource::texture::Texture,
+    scene::{
+        base::BaseBuilder,
+        mesh::{
+            surface::{SurfaceBuilder, SurfaceData, SurfaceSharedData},
+            MeshBuilder,
+        },
+        node::Node,
+        transform::TransformBuilder,
+        Scene,
+    },
+};
+use std::{fmt, path::Path};
+
+#[derive(Debug)]
+pub enum GltfImportError {
+    Io(std::io::Error),
+    Gltf(gltf::Error),
+    /// The document has content the importer can't turn into a scene: no scenes at all, or a
+    /// primitive without a `POSITION` attribute.
+    UnsupportedPrimitive(String),
+}
+
+impl fmt::Display for GltfImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GltfImportError::Io(e) => write!(f, "I/O error while reading glTF file: {}", e),
+            GltfImportError::Gltf(e) => write!(f, "Failed to parse glTF document: {}", e),
+            GltfImportError::UnsupportedPrimitive(reason) => {
+                write!(f, "Unsupported glTF primitive: {}", reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GltfImportError {}
+
+impl From<std::io::Error> for GltfImportError {
+    fn from(e: std::io::Error) -> Self {
+        GltfImportError::Io(e)
+    }
+}
+
+impl From<gltf::Error> for GltfImportError {
+    fn from(e: gltf::Error) -> Self {
+        GltfImportError::Gltf(e)
+    }
+}
+
+/// Imports a `.gltf`/`.glb` file at `path` into a fresh native [`Scene`], resolving texture URIs
+/// relative to `asset_folder` (the project's asset root) via `resource_manager`.
+pub fn import_gltf(
+    path: &Path,
+    asset_folder: &Path,
+    resource_manager: ResourceManager,
+) -> Result<Scene, GltfImportError> {
+    let (document, buffers, _images) = gltf::import(path)?;
+
+    let mut scene = Scene::new();
+
+    let default_gltf_scene = document
+        .default_scene()
+        .or_else(|| document.scenes().next())
+        .ok_or_else(|| GltfImportError::UnsupportedPrimitive("document has no scenes".into()))?;
+
+    for node in default_gltf_scene.nodes() {
+        import_node(
+            &node,
+            scene.graph.get_root(),
+            &mut scene,
+            &buffers,
+            asset_folder,
+            &resource_manager,
+        )?;
+    }
+
+    Ok(scene)
+}
+
+fn import_node(
+    gltf_node: &gltf::Node,
+    parent: Handle<Node>,
+    scene: &mut Scene,
+    buffers: &[gltf::buffer::Data],
+    asset_folder: &Path,
+    resource_manager: &ResourceManager,
+) -> Result<Handle<Node>, GltfImportError> {
+    let (translation, rotation, scale) = gltf_node.transform().decomposed();
+
+    let mut base = BaseBuilder::new().with_name(gltf_node.name().unwrap_or("GltfNode"));
+
+    // If this node was produced by our own exporter, it stashed its Fyrox handle in `extras` -
+    // carry that forward as the node's tag so a later re-export can reuse the same extras value
+    // instead of fabricating a new one from a (by then different) handle.
+    if let Some(tag) = read_fyrox_extras_tag(gltf_node) {
+        base = base.with_tag(tag);
+    }
+
+    let base = base.with_local_transform(
+        TransformBuilder::new()
+            .with_local_position(Vector3::new(translation[0], translation[1], translation[2]))
+            .with_local_rotation(UnitQuaternion::from_quaternion(
+                fyrox::core::algebra::Quaternion::new(
+                    rotation[3],
+                    rotation[0],
+                    rotation[1],
+                    rotation[2],
+                ),
+            ))
+            .with_local_scale(Vector3::new(scale[0], scale[1], scale[2]))
+            .build(),
+    );
+
+    let handle = if let Some(mesh) = gltf_node.mesh() {
+        import_mesh(&mesh, base, buffers, asset_folder, resource_manager, scene)?
+    } else {
+        base.build(&mut scene.graph)
+    };
+
+    scene.graph.link_nodes(handle, parent);
+
+    for child in gltf_node.children() {
+        import_node(
+            &child,
+            handle,
+            scene,
+            buffers,
+            asset_folder,
+            resource_manager,
+        )?;
+    }
+
+    Ok(handle)
+}
+
+fn import_mesh(
+    gltf_mesh: &gltf::Mesh,
+    base: BaseBuilder,
+    buffers: &[gltf::buffer::Data],
+    asset_folder: &Path,
+    resource_manager: &ResourceManager,
+    scene: &mut Scene,
+) -> Result<Handle<Node>, GltfImportError> {
+    let mut surfaces = Vec::new();
+
+    for primitive in gltf_mesh.primitives() {
+        let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+
+        let positions = reader
+            .read_positions()
+            .ok_or_else(|| GltfImportError::UnsupportedPrimitive("primitive has no POSITION attribute".into()))?
+            .map(Vector3::from)
+            .collect::<Vec<_>>();
+
+        let normals = reader
+            .read_normals()
+            .map(|iter| iter.map(Vector3::from).collect::<Vec<_>>())
+            .unwrap_or_else(|| vec![Vector3::y(); positions.len()]);
+
+        let tex_coords = reader
+            .read_tex_coords(0)
+            .map(|iter| iter.into_f32().map(Vector2::from).collect::<Vec<_>>())
+            .unwrap_or_else(|| vec![Vector2::default(); positions.len()]);
+
+        let indices = reader
+            .read_indices()
+            .map(|iter| iter.into_u32().collect::<Vec<_>>())
+            .unwrap_or_else(|| (0..positions.len() as u32).collect());
+
+        let data = SurfaceData::from_raw_mesh(positions, normals, tex_coords, indices);
+
+        let mut surface = SurfaceBuilder::new(SurfaceSharedData::new(data)).build();
+
+        if let Some(base_color) = primitive.material().pbr_metallic_roughness().base_color_texture()
+        {
+            if let Some(texture) =
+                resolve_texture(&base_color.texture(), asset_folder, resource_manager)
+            {
+                surface.material().data_ref().set_texture("diffuseTexture", Some(texture));
+            }
+        }
+
+        surfaces.push(surface);
+    }
+
+    Ok(MeshBuilder::new(base).with_surfaces(surfaces).build(&mut scene.graph))
+}
+
+/// Reads back the `extras` entry `export_gltf` writes for every node (see `export.rs`), so a
+/// round-tripped import/export cycle preserves the original tag instead of each export inventing
+/// a fresh one from that import's (arbitrary) handle.
+fn read_fyrox_extras_tag(gltf_node: &gltf::Node) -> Option<String> {
+    let extras = gltf_node.extras().as_ref()?;
+    let value: serde_json::Value = serde_json::from_str(extras.get()).ok()?;
+    value
+        .get(FYROX_NODE_EXTRAS_KEY)
+        .and_then(|v| v.as_str())
+        .map(str::to_owned)
+}
+
+fn resolve_texture(
+    gltf_texture: &gltf::Texture,
+    asset_folder: &Path,
+    resource_manager: &ResourceManager,
+) -> Option<Texture> {
+    match gltf_texture.source().source() {
+        gltf::image::Source::Uri { uri, .. } => {
+            let path = asset_folder.join(uri);
+            Some(resource_manager.request(path))
+        }
+        // Embedded/buffer-view images are already decoded by `gltf::import` into `_images`;
+        // writing them out to the asset folder so they can go through the normal resource
+        // pipeline is left to the caller, since naming them is a project-specific policy.
+        gltf::image::Source::View { .. } => None,
+    }
+}
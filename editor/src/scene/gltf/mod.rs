@@ -0,0 +1,18 @@
+//! glTF 2.0 import/export support for [`EditorScene`](crate::scene::EditorScene).
+//!
+//! The importer turns a `.gltf`/`.glb` document into a native Fyrox [`Scene`] graph that can be
+//! handed straight to [`EditorScene::from_native_scene`](crate::scene::EditorScene::from_native_scene),
+//! while the exporter walks a scene's graph back into a glTF document. Node names and a small
+//! "extras" payload are preserved on import so that re-exporting the same scene produces a
+//! document that round-trips cleanly through a DCC tool.
+
+mod export;
+mod import;
+
+pub use export::{export_gltf, GltfExportError};
+pub use import::{import_gltf, GltfImportError};
+
+/// Key used in a glTF node's `extras` object to stash the `index:generation` pair of the handle
+/// the node had when first exported (an import carries it forward as the node's tag), so a later
+/// export of the same scene can restore node identity instead of renumbering everything.
+const FYROX_NODE_EXTRAS_KEY: &str = "fyrox_node_handle";
@@ -0,0 +1,162 @@
+use crate::{
+    scene::{
+        trigger_volume::{SceneTransitionMode, SceneTransitionRequest},
+        EditorScene,
+    },
+    GameEngine,
+};
+use fyrox::{core::visitor::Visitor, scene::Scene};
+use std::path::{Path, PathBuf};
+
+/// Holds several concurrently-loaded [`EditorScene`]s so large worlds can be authored split
+/// across files: exactly one is "active" (the one commands and the scene tree panel operate on),
+/// the rest are loaded read-only so the active scene can be edited with the right spatial and
+/// navigational context around it.
+#[derive(Default)]
+pub struct SceneManager {
+    scenes: Vec<EditorScene>,
+    active: Option<usize>,
+}
+
+impl SceneManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn active_index(&self) -> Option<usize> {
+        self.active
+    }
+
+    pub fn active_scene(&self) -> Option<&EditorScene> {
+        self.active.map(|index| &self.scenes[index])
+    }
+
+    pub fn active_scene_mut(&mut self) -> Option<&mut EditorScene> {
+        self.active.map(move |index| &mut self.scenes[index])
+    }
+
+    /// Scenes that are loaded for spatial/navigational context but aren't the one being edited.
+    pub fn context_scenes(&self) -> impl Iterator<Item = &EditorScene> {
+        self.scenes
+            .iter()
+            .enumerate()
+            .filter(move |(index, _)| Some(*index) != self.active)
+            .map(|(_, scene)| scene)
+    }
+
+    pub fn scene_by_path(&self, path: &Path) -> Option<usize> {
+        self.scenes
+            .iter()
+            .position(|scene| scene.path.as_deref() == Some(path))
+    }
+
+    /// Additively loads `path` alongside whatever is already loaded. The newly loaded scene
+    /// becomes active if `make_active` is set or no scene is active yet - otherwise it joins the
+    /// others as read-only context. `scene` is expected to already have been deserialized from
+    /// `path` (as [`Self::apply_transition_request`] does before calling this) - `path` itself is
+    /// only recorded for later lookup by [`Self::scene_by_path`].
+    pub fn load_additive(
+        &mut self,
+        path: PathBuf,
+        mut scene: EditorScene,
+        make_active: bool,
+    ) -> usize {
+        scene.path = Some(path);
+
+        let index = self.scenes.len();
+        self.scenes.push(scene);
+
+        if make_active || self.active.is_none() {
+            self.active = Some(index);
+        }
+
+        index
+    }
+
+    /// Applies a [`SceneTransitionRequest`] a [`crate::scene::trigger_volume::LevelTriggerVolume`]
+    /// raised at runtime: loads/unloads/switches the scene it names, resolved relative to
+    /// `asset_folder`. The editor is the natural place for this dispatch since it's already the
+    /// thing juggling several concurrently-loaded [`EditorScene`]s; a shipped game wires the same
+    /// request to whatever scene-loading plugin it ships instead.
+    pub fn apply_transition_request(
+        &mut self,
+        request: &SceneTransitionRequest,
+        asset_folder: &Path,
+        engine: &mut GameEngine,
+    ) -> Result<(), String> {
+        let path = asset_folder.join(&request.target_scene);
+
+        match request.mode {
+            SceneTransitionMode::Unload => {
+                if let Some(index) = self.scene_by_path(&path) {
+                    self.unload(index, engine);
+                }
+                Ok(())
+            }
+            SceneTransitionMode::LoadAdditive => {
+                if self.scene_by_path(&path).is_none() {
+                    let scene = load_scene_from_path(&path, engine)?;
+                    self.load_additive(path, scene, false);
+                }
+                Ok(())
+            }
+            SceneTransitionMode::Switch => {
+                for index in (0..self.scenes.len()).rev() {
+                    self.unload(index, engine);
+                }
+
+                let scene = load_scene_from_path(&path, engine)?;
+                self.load_additive(path, scene, true);
+                Ok(())
+            }
+        }
+    }
+
+    /// Unloads the scene at `index`. If it was active, the manager is left with no active scene
+    /// (any other loaded scenes stay loaded) - the caller is expected to pick a new active scene
+    /// (e.g. via [`Self::set_active`]) right after.
+    pub fn unload(&mut self, index: usize, engine: &mut GameEngine) {
+        let scene = self.scenes.remove(index);
+        engine.scenes.remove(scene.scene);
+
+        self.active = match self.active {
+            Some(active) if active == index => None,
+            Some(active) if active > index => Some(active - 1),
+            other => other,
+        };
+    }
+
+    /// Switches editing focus to the scene at `index`, which must already be loaded (additively
+    /// or otherwise). Does not load or unload anything on its own - pair [`Self::unload`] with
+    /// [`Self::load_additive`] for a true "switch" that drops everything else.
+    pub fn set_active(&mut self, index: usize) {
+        assert!(index < self.scenes.len());
+        self.active = Some(index);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.scenes.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &EditorScene> {
+        self.scenes.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut EditorScene> {
+        self.scenes.iter_mut()
+    }
+}
+
+/// Deserializes the native scene file at `path` and wraps it as an [`EditorScene`], mirroring
+/// how [`EditorScene::save`] writes one out.
+fn load_scene_from_path(path: &Path, engine: &mut GameEngine) -> Result<EditorScene, String> {
+    let mut visitor = Visitor::load_binary(path)
+        .map_err(|e| format!("Failed to load scene {}: {}", path.display(), e))?;
+
+    let mut scene = Scene::default();
+    scene
+        .visit("Scene", &mut visitor)
+        .map_err(|e| format!("Failed to deserialize scene {}: {}", path.display(), e))?;
+
+    Ok(EditorScene::from_native_scene(scene, engine, Some(path.to_owned())))
+}
@@ -27,6 +27,17 @@ pub mod clipboard;
 #[macro_use]
 pub mod commands;
 
+pub mod gltf;
+pub mod manager;
+pub mod save_config;
+pub mod shadow;
+pub mod trigger_volume;
+pub mod validation;
+
+use save_config::{apply_filters_and_cleanup, SaveConfig, SceneSaved};
+use std::sync::mpsc::Sender;
+use validation::{Diagnostic, DiagnosticSeverity, ValidationRegistry};
+
 pub struct EditorScene {
     pub path: Option<PathBuf>,
     pub scene: Handle<Scene>,
@@ -36,6 +47,9 @@ pub struct EditorScene {
     pub clipboard: Clipboard,
     pub camera_controller: CameraController,
     pub navmeshes: Pool<Navmesh>,
+    // Diagnostics produced by the last validation pass, kept around so the UI can let the
+    // user jump to the node a warning or error points at.
+    pub diagnostics: Vec<Diagnostic>,
 }
 
 impl EditorScene {
@@ -78,21 +92,83 @@ impl EditorScene {
             scene: engine.scenes.add(scene),
             selection: Default::default(),
             clipboard: Default::default(),
+            diagnostics: Default::default(),
+        }
+    }
+
+    /// Diagnostics produced by the most recent call to [`EditorScene::save`], in the order the
+    /// validation rules were registered.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// Sets [`Self::selection`] to the node a [`Diagnostic`] points at, the same way any other
+    /// selection change would, so the world viewer/outliner jump to it once something calls this
+    /// in response to a diagnostic being clicked. No such caller (e.g. a diagnostics panel) exists
+    /// in this tree yet - this only provides the hook. Diagnostics with no associated node (e.g.
+    /// [`validation::NavmeshIntegrityRule`]'s, which point at a navmesh rather than a graph node)
+    /// leave the current selection untouched.
+    pub fn select_diagnostic(&mut self, diagnostic: &Diagnostic) {
+        if diagnostic.node.is_some() {
+            self.selection = Selection::Graph(GraphSelection::from_list(vec![diagnostic.node]));
         }
     }
 
-    pub fn save(&mut self, path: PathBuf, engine: &mut GameEngine) -> Result<String, String> {
+    /// Imports a `.gltf`/`.glb` file as a new [`EditorScene`], resolving texture URIs against
+    /// `asset_folder` (the project's asset root).
+    pub fn from_gltf(
+        path: PathBuf,
+        asset_folder: &std::path::Path,
+        engine: &mut Engine,
+    ) -> Result<Self, gltf::GltfImportError> {
+        let scene = gltf::import_gltf(&path, asset_folder, engine.resource_manager.clone())?;
+        Ok(Self::from_native_scene(scene, engine, Some(path)))
+    }
+
+    /// Exports the current (editor-node-free) scene to a `.gltf` document at `path`, for taking
+    /// work back out into a DCC tool.
+    pub fn export_gltf(
+        &self,
+        path: &std::path::Path,
+        engine: &GameEngine,
+    ) -> Result<(), gltf::GltfExportError> {
+        let scene = &engine.scenes[self.scene];
+        let (pure_scene, _) = scene.clone(&mut |node, _| node != self.root);
+        gltf::export_gltf(&pure_scene, path)
+    }
+
+    pub fn save(
+        &mut self,
+        path: PathBuf,
+        config: SaveConfig,
+        message_sender: &Sender<SceneSaved>,
+        engine: &mut GameEngine,
+    ) -> Result<SceneSaved, String> {
         let scene = &mut engine.scenes[self.scene];
 
-        // Validate first.
-        let valid = true;
+        // Validate first, running every registered rule over the scene as it stands right now.
+        let diagnostics = ValidationRegistry::with_default_rules().run(scene, self);
+        let valid = !diagnostics
+            .iter()
+            .any(|d| d.severity == DiagnosticSeverity::Error);
+        self.diagnostics = diagnostics;
+
         let mut reason = "Scene is not saved, because validation failed:\n".to_owned();
+        for diagnostic in self
+            .diagnostics
+            .iter()
+            .filter(|d| d.severity == DiagnosticSeverity::Error)
+        {
+            writeln!(&mut reason, "{}", diagnostic.message).unwrap();
+        }
 
         if valid {
             self.path = Some(path.clone());
 
-            let editor_root = self.root;
-            let (mut pure_scene, _) = scene.clone(&mut |node, _| node != editor_root);
+            let (mut pure_scene, old_to_new) =
+                scene.clone(&mut |handle, node| (config.node_filter)(handle, node));
+
+            apply_filters_and_cleanup(&scene.graph, &mut pure_scene, &old_to_new, &config);
 
             // Reset state of nodes. For some nodes (such as particles systems) we use scene as preview
             // so before saving scene, we have to reset state of such nodes.
@@ -136,12 +212,16 @@ impl EditorScene {
                     .add(fyrox::utils::navmesh::Navmesh::new(&triangles, &vertices));
             }
 
+            let node_count = pure_scene.graph.linear_iter().count();
+
             let mut visitor = Visitor::new();
             pure_scene.save("Scene", &mut visitor).unwrap();
             if let Err(e) = visitor.save_binary(&path) {
                 Err(format!("Failed to save scene! Reason: {}", e))
             } else {
-                Ok(format!("Scene {} was successfully saved!", path.display()))
+                let saved = SceneSaved { path, node_count };
+                let _ = message_sender.send(saved.clone());
+                Ok(saved)
             }
         } else {
             writeln!(&mut reason, "\nPlease fix errors and try again.").unwrap();
@@ -0,0 +1,145 @@
+use fyrox::{
+    core::pool::Handle,
+    scene::{graph::Graph, node::Node, Scene},
+};
+use std::{collections::HashMap, fmt, path::PathBuf};
+
+/// Lets a caller of [`EditorScene::save`](crate::scene::EditorScene::save) customize what ends
+/// up in the serialized scene, beyond the single fixed "skip the editor root" predicate the
+/// previous implementation hard-coded.
+pub struct SaveConfig<'a> {
+    /// The editor's root node. The default `node_filter` built by [`SaveConfig::new`] excludes it
+    /// from the save along with everything the editor parented under it; `EditorScene::save`
+    /// does not read this field itself, so a filter set via [`Self::with_node_filter`] alone
+    /// decides whether this node is kept.
+    pub root: Handle<Node>,
+    /// Called once per node; returning `false` drops the node (and, after cleanup, reparents any
+    /// surviving children up to the nearest ancestor that's kept).
+    pub node_filter: Box<dyn Fn(Handle<Node>, &Node) -> bool + 'a>,
+    /// Called once per node with the name of its script component (if any); returning `false`
+    /// strips that script before serialization, so purely-editor or debug-only behaviours never
+    /// make it into the saved file even when the node that hosts them is kept.
+    pub component_filter: Box<dyn Fn(&str) -> bool + 'a>,
+}
+
+impl<'a> SaveConfig<'a> {
+    /// A config that reproduces the editor's previous behaviour: keep everything except `root`.
+    pub fn new(root: Handle<Node>) -> Self {
+        Self {
+            root,
+            node_filter: Box::new(move |handle, _| handle != root),
+            component_filter: Box::new(|_| true),
+        }
+    }
+
+    pub fn with_node_filter(
+        mut self,
+        filter: impl Fn(Handle<Node>, &Node) -> bool + 'a,
+    ) -> Self {
+        self.node_filter = Box::new(filter);
+        self
+    }
+
+    pub fn with_component_filter(mut self, filter: impl Fn(&str) -> bool + 'a) -> Self {
+        self.component_filter = Box::new(filter);
+        self
+    }
+}
+
+/// Structured record of a completed save, handed to other editor subsystems (via a message/event
+/// channel) instead of forcing them to scrape a human-readable string.
+#[derive(Debug, Clone)]
+pub struct SceneSaved {
+    pub path: PathBuf,
+    pub node_count: usize,
+}
+
+impl fmt::Display for SceneSaved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Scene {} was successfully saved! ({} nodes)",
+            self.path.display(),
+            self.node_count
+        )
+    }
+}
+
+/// Applies `config.component_filter` to every surviving node, stripping scripts the filter
+/// rejects, then walks the graph fixing up any node whose parent was filtered out: it's
+/// reparented to the nearest surviving ancestor (or the scene root if none survived), so no
+/// invalid [`Handle<Node>`] is left pointing at a node that no longer exists.
+///
+/// `original_graph` is the *pre-filter* graph `old_to_new` was produced against - handles from
+/// `pure_scene.graph` (the clone) and `original_graph` live in different handle spaces, since
+/// `Scene::clone` assigns the clone fresh handles. `old_to_new` is the only thing that correctly
+/// translates between the two, so every lookup below goes through it rather than assuming index
+/// identity across the two graphs.
+pub(super) fn apply_filters_and_cleanup(
+    original_graph: &Graph,
+    pure_scene: &mut Scene,
+    old_to_new: &HashMap<Handle<Node>, Handle<Node>>,
+    config: &SaveConfig,
+) {
+    for node in pure_scene.graph.linear_iter_mut() {
+        if let Some(script) = node.script() {
+            let type_name = script.id();
+            if !(config.component_filter)(type_name) {
+                node.set_script(None);
+            }
+        }
+    }
+
+    let valid_root = pure_scene.graph.get_root();
+    let new_to_old: HashMap<Handle<Node>, Handle<Node>> =
+        old_to_new.iter().map(|(&old, &new)| (new, old)).collect();
+
+    let reparents: Vec<(Handle<Node>, Handle<Node>)> = pure_scene
+        .graph
+        .pair_iter()
+        .filter(|(_, node)| !pure_scene.graph.is_valid_handle(node.parent()))
+        .filter_map(|(new_handle, _)| {
+            let old_handle = *new_to_old.get(&new_handle)?;
+
+            let mut ancestor = original_graph[old_handle].parent();
+            while ancestor.is_some() && !old_to_new.contains_key(&ancestor) {
+                ancestor = original_graph[ancestor].parent();
+            }
+
+            let target = old_to_new.get(&ancestor).copied().unwrap_or(valid_root);
+
+            Some((new_handle, target))
+        })
+        .filter(|(handle, target)| handle != target)
+        .collect();
+
+    for (handle, new_parent) in reparents {
+        pure_scene.graph.link_nodes(handle, new_parent);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fyrox::scene::{base::BaseBuilder, pivot::PivotBuilder};
+
+    #[test]
+    fn reparents_orphan_to_nearest_surviving_ancestor() {
+        let mut scene = Scene::default();
+        let root = PivotBuilder::new(BaseBuilder::new()).build(&mut scene.graph);
+        let middle = PivotBuilder::new(BaseBuilder::new()).build(&mut scene.graph);
+        let leaf = PivotBuilder::new(BaseBuilder::new()).build(&mut scene.graph);
+        scene.graph.link_nodes(middle, root);
+        scene.graph.link_nodes(leaf, middle);
+
+        let (mut pure_scene, old_to_new) = scene.clone(&mut |handle, _| handle != middle);
+        let config = SaveConfig::new(Handle::NONE);
+
+        apply_filters_and_cleanup(&scene.graph, &mut pure_scene, &old_to_new, &config);
+
+        assert!(!old_to_new.contains_key(&middle));
+        let new_root = old_to_new[&root];
+        let new_leaf = old_to_new[&leaf];
+        assert_eq!(pure_scene.graph[new_leaf].parent(), new_root);
+    }
+}
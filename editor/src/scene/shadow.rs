@@ -0,0 +1,270 @@
+//! Shadow filtering settings authored per-light from the inspector, plus the kernel math the
+//! renderer's shadow map pass samples against. Kept alongside the scene model (rather than in the
+//! renderer itself) so it serializes with the scene like any other light property.
+
+use fyrox::{
+    core::{algebra::Vector2, reflect::Reflect, uuid::Uuid, visitor::prelude::*, TypeUuidProvider},
+    impl_component_provider,
+    scene::light::BaseLight,
+    script::{ScriptContext, ScriptTrait},
+};
+
+/// How a light's shadow map edges are filtered.
+#[derive(Visit, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShadowFilterMode {
+    /// No filtering - a single hard-edged depth comparison.
+    None,
+    /// Fixed 2x2 hardware PCF, cheapest option with soft edges.
+    #[default]
+    Hardware2x2,
+    /// Software PCF over a rotated Poisson-disc kernel.
+    Pcf,
+    /// PCSS: a blocker search estimates penumbra width, which then scales a PCF kernel.
+    Pcss,
+}
+
+#[derive(Visit, Reflect, Debug, Clone)]
+pub struct LightShadowSettings {
+    pub filter: ShadowFilterMode,
+    /// Depth bias added before the shadow-map comparison, to combat shadow acne.
+    pub depth_bias: f32,
+    /// Number of taps in the Poisson-disc kernel used by [`ShadowFilterMode::Pcf`] and
+    /// [`ShadowFilterMode::Pcss`]. Regenerating the kernel is only needed when this changes.
+    pub sample_count: u32,
+    /// World-space size of the light, used by PCSS to turn penumbra *angle* into penumbra
+    /// *width* at a given receiver/blocker distance.
+    pub light_size: f32,
+    /// Base Poisson-disc kernel for the current `sample_count`, rotated per-frame by
+    /// [`ScriptTrait::on_update`] and exposed via [`Self::rotated_kernel`] for the renderer's
+    /// shadow pass to sample against.
+    #[visit(skip)]
+    #[reflect(hidden)]
+    kernel: Vec<Vector2<f32>>,
+    #[visit(skip)]
+    #[reflect(hidden)]
+    rotated_kernel: Vec<Vector2<f32>>,
+    /// Last computed PCSS penumbra width, scaling [`Self::rotated_kernel`]'s effective radius.
+    #[visit(skip)]
+    #[reflect(hidden)]
+    penumbra_width: f32,
+}
+
+impl Default for LightShadowSettings {
+    fn default() -> Self {
+        Self {
+            filter: ShadowFilterMode::default(),
+            depth_bias: 0.0025,
+            sample_count: 16,
+            light_size: 0.25,
+            kernel: Vec::new(),
+            rotated_kernel: Vec::new(),
+            penumbra_width: 0.0,
+        }
+    }
+}
+
+impl LightShadowSettings {
+    pub const FILTER: &'static str = "filter";
+    pub const DEPTH_BIAS: &'static str = "depth_bias";
+    pub const SAMPLE_COUNT: &'static str = "sample_count";
+    pub const LIGHT_SIZE: &'static str = "light_size";
+
+    /// Minimum distance between accepted Poisson-disc samples, as a fraction of the unit disc.
+    /// Denser kernels (higher `sample_count`) need a tighter minimum distance to still fit.
+    fn min_sample_distance(sample_count: u32) -> f32 {
+        1.0 / (sample_count as f32).sqrt()
+    }
+
+    /// (Re)builds [`Self::kernel`] for the current `sample_count`. Cheap to call unconditionally
+    /// since it only regenerates when the cached kernel's length is stale.
+    fn regenerate_kernel(&mut self) {
+        if self.kernel.len() != self.sample_count as usize {
+            self.kernel =
+                generate_poisson_disk(self.sample_count, Self::min_sample_distance(self.sample_count));
+            self.rotated_kernel = self.kernel.clone();
+        }
+    }
+
+    /// The kernel the shadow map pass should sample this frame: the base Poisson-disc kernel
+    /// rotated by the light's rotation angle as of the last [`ScriptTrait::on_update`]. That one
+    /// rotation applies to every fragment; no per-fragment (screen-position) rotation is applied
+    /// to it. The renderer scales its sample radius separately by [`Self::penumbra_width`]
+    /// for [`ShadowFilterMode::Pcss`] - this kernel is not itself pre-scaled.
+    pub fn rotated_kernel(&self) -> &[Vector2<f32>] {
+        &self.rotated_kernel
+    }
+
+    /// Width of the PCSS penumbra, as of the last call to [`Self::set_measured_penumbra`]; `0.0`
+    /// until then, and whenever [`Self::filter`](LightShadowSettings::filter) isn't
+    /// [`ShadowFilterMode::Pcss`].
+    pub fn penumbra_width(&self) -> f32 {
+        self.penumbra_width
+    }
+
+    /// Records this frame's PCSS penumbra width from a blocker search the renderer already ran
+    /// against the populated shadow map. There's no shadow map here in script-land to search -
+    /// [`ScriptTrait::on_update`] runs before the frame is rendered - so this is the hook the
+    /// renderer calls once it has real `receiver_depth`/`blocker_depth` samples, rather than
+    /// `on_update` guessing at a value that can't reflect actual scene geometry.
+    pub fn set_measured_penumbra(&mut self, receiver_depth: f32, blocker_depth: f32) {
+        self.penumbra_width = if self.filter == ShadowFilterMode::Pcss {
+            pcss_penumbra_width(receiver_depth, blocker_depth, self.light_size)
+        } else {
+            0.0
+        };
+    }
+}
+
+impl_component_provider!(LightShadowSettings);
+
+impl TypeUuidProvider for LightShadowSettings {
+    fn type_uuid() -> Uuid {
+        fyrox::core::uuid!("2f6a0a8e-8b3d-4c36-9f2b-1a6c6b6f9b6e")
+    }
+}
+
+impl ScriptTrait for LightShadowSettings {
+    fn on_init(&mut self, _ctx: &mut ScriptContext) {
+        self.regenerate_kernel();
+    }
+
+    // The actual shadow-map sampling lives in the (out-of-this-crate) renderer, which queries
+    // `rotated_kernel`/`penumbra_width` when it builds this light's shadow pass and calls
+    // `set_measured_penumbra` once it has real depths to compute the latter from. What happens
+    // here each frame is everything that's genuinely available before the frame is rendered:
+    // regenerating the kernel if `sample_count` changed, rotating it by the light's current
+    // rotation (so the noise pattern doesn't swim as the light moves), and pushing `depth_bias`
+    // onto the node's `BaseLight`.
+    fn on_update(&mut self, ctx: &mut ScriptContext) {
+        self.regenerate_kernel();
+
+        let rotation_angle = ctx
+            .scene
+            .graph
+            .try_get(ctx.handle)
+            .map(|node| node.local_transform().rotation().euler_angles().1)
+            .unwrap_or(0.0);
+        self.rotated_kernel = rotate_kernel(&self.kernel, rotation_angle);
+
+        if let Some(light) = ctx
+            .scene
+            .graph
+            .try_get_mut(ctx.handle)
+            .and_then(|node| node.query_component_mut::<BaseLight>())
+        {
+            light.set_shadow_bias(self.depth_bias);
+        }
+    }
+}
+
+/// Generates `sample_count` points on a unit disc using a simple dart-throwing Poisson-disc
+/// distribution (reject points closer than `min_distance` to any previously accepted one).
+/// [`LightShadowSettings`] re-runs this only when its `sample_count` changes and otherwise
+/// reuses the cached kernel.
+pub fn generate_poisson_disk(sample_count: u32, min_distance: f32) -> Vec<Vector2<f32>> {
+    // A deterministic LCG keeps kernel generation reproducible across runs/platforms instead of
+    // pulling in a full RNG dependency for what's a few hundred draws at most.
+    let mut state: u32 = 0x9E3779B9;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        (state as f32 / u32::MAX as f32) * 2.0 - 1.0
+    };
+
+    let mut points = Vec::with_capacity(sample_count as usize);
+    let mut attempts = 0;
+
+    while (points.len() as u32) < sample_count && attempts < sample_count * 1000 {
+        attempts += 1;
+
+        let candidate = Vector2::new(next(), next());
+        if candidate.norm() > 1.0 {
+            continue;
+        }
+
+        if points
+            .iter()
+            .all(|p: &Vector2<f32>| (p - candidate).norm() >= min_distance)
+        {
+            points.push(candidate);
+        }
+    }
+
+    points
+}
+
+/// Rotates every sample in `kernel` by `angle` radians. [`LightShadowSettings`]'s `on_update`
+/// calls it once per frame with the light's rotation angle.
+pub fn rotate_kernel(kernel: &[Vector2<f32>], angle: f32) -> Vec<Vector2<f32>> {
+    let (sin, cos) = angle.sin_cos();
+    kernel
+        .iter()
+        .map(|p| Vector2::new(p.x * cos - p.y * sin, p.x * sin + p.y * cos))
+        .collect()
+}
+
+/// Computes the PCSS penumbra width given the receiver depth, the average depth of occluders
+/// found by the blocker search, and the light's world-space size: `w = (z_receiver - z_blocker) /
+/// z_blocker * light_size`. The result scales the PCF kernel radius for the final filter pass, so
+/// shadows widen the further the receiver is from its occluder.
+pub fn pcss_penumbra_width(receiver_depth: f32, blocker_depth: f32, light_size: f32) -> f32 {
+    if blocker_depth <= 0.0 {
+        return 0.0;
+    }
+
+    ((receiver_depth - blocker_depth) / blocker_depth) * light_size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn poisson_disk_respects_sample_count_and_min_distance() {
+        let min_distance = 0.2;
+        let kernel = generate_poisson_disk(16, min_distance);
+
+        assert!(kernel.len() <= 16);
+        assert!(!kernel.is_empty());
+
+        for point in &kernel {
+            assert!(point.norm() <= 1.0);
+        }
+
+        for (i, a) in kernel.iter().enumerate() {
+            for b in &kernel[i + 1..] {
+                assert!((a - b).norm() >= min_distance);
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_kernel_preserves_sample_distances() {
+        let kernel = generate_poisson_disk(8, 0.2);
+        let rotated = rotate_kernel(&kernel, std::f32::consts::FRAC_PI_2);
+
+        assert_eq!(kernel.len(), rotated.len());
+        for (original, rotated) in kernel.iter().zip(&rotated) {
+            assert!((original.norm() - rotated.norm()).abs() < 1e-5);
+        }
+
+        // A quarter turn maps (x, y) to (-y, x).
+        let point = Vector2::new(1.0, 0.0);
+        let turned = &rotate_kernel(&[point], std::f32::consts::FRAC_PI_2)[0];
+        assert!((turned.x - 0.0).abs() < 1e-5);
+        assert!((turned.y - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn pcss_penumbra_width_grows_with_receiver_blocker_gap() {
+        assert_eq!(pcss_penumbra_width(1.0, 0.0, 1.0), 0.0);
+
+        let near = pcss_penumbra_width(1.0, 0.9, 1.0);
+        let far = pcss_penumbra_width(1.0, 0.1, 1.0);
+        assert!(far > near);
+
+        // Receiver touching the blocker casts no penumbra.
+        assert!(pcss_penumbra_width(1.0, 1.0, 1.0).abs() < 1e-6);
+    }
+}
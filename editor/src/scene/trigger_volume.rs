@@ -0,0 +1,78 @@
+//! The authorable "level transition trigger" - a volume that streams another scene file in or
+//! out when something enters it at runtime. It's implemented as a [`ScriptTrait`] so it can be
+//! attached to any existing volume node (a trigger `Collider`, typically) instead of requiring a
+//! dedicated core node type.
+
+use fyrox::{
+    core::{reflect::Reflect, uuid::Uuid, visitor::prelude::*, TypeUuidProvider},
+    impl_component_provider,
+    script::{ScriptContext, ScriptTrait},
+};
+use std::path::PathBuf;
+
+/// What happens to [`LevelTriggerVolume::target_scene`] when something enters the volume.
+#[derive(Visit, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SceneTransitionMode {
+    /// Load the target scene alongside whatever is already loaded.
+    #[default]
+    LoadAdditive,
+    /// Unload the target scene if it's currently loaded.
+    Unload,
+    /// Unload everything else and load the target scene in its place.
+    Switch,
+}
+
+#[derive(Visit, Reflect, Debug, Clone, Default)]
+pub struct LevelTriggerVolume {
+    /// Path (relative to the asset folder) of the scene this trigger streams in or out.
+    pub target_scene: PathBuf,
+    pub mode: SceneTransitionMode,
+    #[visit(skip)]
+    #[reflect(hidden)]
+    triggered: bool,
+}
+
+impl LevelTriggerVolume {
+    pub const TARGET_SCENE: &'static str = "target_scene";
+    pub const MODE: &'static str = "mode";
+}
+
+impl_component_provider!(LevelTriggerVolume);
+
+impl TypeUuidProvider for LevelTriggerVolume {
+    fn type_uuid() -> Uuid {
+        fyrox::core::uuid!("9c6f5b9e-5f1a-4b3a-9b0a-4a2a4e6e7a11")
+    }
+}
+
+impl ScriptTrait for LevelTriggerVolume {
+    fn on_update(&mut self, ctx: &mut ScriptContext) {
+        let overlapping = ctx
+            .scene
+            .graph
+            .try_get(ctx.handle)
+            .and_then(|node| node.cast::<fyrox::scene::collider::Collider>())
+            .map(|collider| collider.contacts(&ctx.scene.graph.physics).count() > 0)
+            .unwrap_or(false);
+
+        if overlapping && !self.triggered {
+            self.triggered = true;
+            ctx.message_sender.send_global(SceneTransitionRequest {
+                target_scene: self.target_scene.clone(),
+                mode: self.mode,
+            });
+        } else if !overlapping {
+            self.triggered = false;
+        }
+    }
+}
+
+/// Runtime-side request a [`LevelTriggerVolume`] raises when it fires; the level streaming layer
+/// - in-editor, [`crate::scene::manager::SceneManager::apply_transition_request`]; in a shipped
+/// game, whatever scene-loading plugin it ships - is responsible for actually loading/unloading
+/// in response.
+#[derive(Debug, Clone)]
+pub struct SceneTransitionRequest {
+    pub target_scene: PathBuf,
+    pub mode: SceneTransitionMode,
+}
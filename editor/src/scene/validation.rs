@@ -0,0 +1,204 @@
+use crate::scene::EditorScene;
+use fyrox::{
+    core::pool::Handle,
+    scene::{dim2::rectangle::Rectangle, node::Node, Scene},
+};
+
+/// How severe a [`Diagnostic`] is. Only [`DiagnosticSeverity::Error`] blocks a save.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DiagnosticSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+/// A single issue found by a [`SceneValidationRule`], pointing at the node responsible (if any)
+/// so the UI can let the user jump straight to it.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: DiagnosticSeverity,
+    pub message: String,
+    pub node: Handle<Node>,
+}
+
+impl Diagnostic {
+    fn new(severity: DiagnosticSeverity, node: Handle<Node>, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            message: message.into(),
+            node,
+        }
+    }
+}
+
+/// A single check that inspects a scene and reports problems it finds. Each rule sets the
+/// severity of every [`Diagnostic`] it returns; [`ValidationRegistry`] passes those severities
+/// through unchanged and does not remap them.
+pub trait SceneValidationRule: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn check(&self, scene: &Scene, editor_scene: &EditorScene) -> Vec<Diagnostic>;
+}
+
+/// Flags navmeshes with degenerate triangles (repeated vertex handles) or triangles that
+/// reference a vertex handle no longer present in `navmesh.vertices`, which would otherwise
+/// panic the `vertex_map[&triangle.a]` lookup in [`EditorScene::save`].
+pub struct NavmeshIntegrityRule;
+
+impl SceneValidationRule for NavmeshIntegrityRule {
+    fn name(&self) -> &str {
+        "NavmeshIntegrityRule"
+    }
+
+    fn check(&self, _scene: &Scene, editor_scene: &EditorScene) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for (navmesh_handle, navmesh) in editor_scene.navmeshes.pair_iter() {
+            for triangle in navmesh.triangles.iter() {
+                if triangle.a == triangle.b || triangle.b == triangle.c || triangle.a == triangle.c
+                {
+                    diagnostics.push(Diagnostic::new(
+                        DiagnosticSeverity::Error,
+                        Handle::NONE,
+                        format!(
+                            "Navmesh {:?} has a degenerate triangle with repeated vertex handles.",
+                            navmesh_handle
+                        ),
+                    ));
+                }
+
+                for vertex_handle in [triangle.a, triangle.b, triangle.c] {
+                    if !navmesh.vertices.is_valid_handle(vertex_handle) {
+                        diagnostics.push(Diagnostic::new(
+                            DiagnosticSeverity::Error,
+                            Handle::NONE,
+                            format!(
+                                "Navmesh {:?} has a triangle referencing dangling vertex handle {:?}.",
+                                navmesh_handle, vertex_handle
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// Flags `Rectangle` nodes whose texture resource is missing or failed to load. Only `Rectangle`
+/// nodes are checked; textures of other node types (e.g. mesh materials) are not inspected.
+pub struct MissingTextureRule;
+
+impl SceneValidationRule for MissingTextureRule {
+    fn name(&self) -> &str {
+        "MissingTextureRule"
+    }
+
+    fn check(&self, scene: &Scene, _editor_scene: &EditorScene) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for (handle, node) in scene.graph.pair_iter() {
+            if let Some(rectangle) = node.cast::<Rectangle>() {
+                match rectangle.texture() {
+                    Some(texture) if texture.is_failed_to_load() => {
+                        diagnostics.push(Diagnostic::new(
+                            DiagnosticSeverity::Error,
+                            handle,
+                            format!("Rectangle {:?} references a texture that failed to load.", handle),
+                        ));
+                    }
+                    None => {
+                        diagnostics.push(Diagnostic::new(
+                            DiagnosticSeverity::Warning,
+                            handle,
+                            format!("Rectangle {:?} has no texture assigned.", handle),
+                        ));
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// Flags nodes whose parent or children handles no longer resolve to a live node in the graph.
+pub struct OrphanedHandleRule;
+
+impl SceneValidationRule for OrphanedHandleRule {
+    fn name(&self) -> &str {
+        "OrphanedHandleRule"
+    }
+
+    fn check(&self, scene: &Scene, _editor_scene: &EditorScene) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for (handle, node) in scene.graph.pair_iter() {
+            if node.parent().is_some() && !scene.graph.is_valid_handle(node.parent()) {
+                diagnostics.push(Diagnostic::new(
+                    DiagnosticSeverity::Error,
+                    handle,
+                    format!("Node {:?} points at a dangling parent handle.", handle),
+                ));
+            }
+
+            for child in node.children() {
+                if !scene.graph.is_valid_handle(*child) {
+                    diagnostics.push(Diagnostic::new(
+                        DiagnosticSeverity::Error,
+                        handle,
+                        format!(
+                            "Node {:?} has a dangling child handle {:?}.",
+                            handle, child
+                        ),
+                    ));
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// Collects [`SceneValidationRule`]s and runs them all over a scene, returning their combined
+/// diagnostics; [`EditorScene::save`] refuses to save when any of them is an Error.
+pub struct ValidationRegistry {
+    rules: Vec<Box<dyn SceneValidationRule>>,
+}
+
+impl ValidationRegistry {
+    pub fn new() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Registry pre-populated with the rules shipped by the editor itself.
+    pub fn with_default_rules() -> Self {
+        let mut registry = Self::new();
+        registry.register(Box::new(NavmeshIntegrityRule));
+        registry.register(Box::new(MissingTextureRule));
+        registry.register(Box::new(OrphanedHandleRule));
+        registry
+    }
+
+    pub fn register(&mut self, rule: Box<dyn SceneValidationRule>) {
+        self.rules.push(rule);
+    }
+
+    /// Runs every registered rule and returns all diagnostics in registration order. Rules are
+    /// independent of each other, so this is a natural place for a thread pool to fan them out
+    /// once scene/graph access is made `Sync`-friendly; for now they're run in sequence.
+    pub fn run(&self, scene: &Scene, editor_scene: &EditorScene) -> Vec<Diagnostic> {
+        self.rules
+            .iter()
+            .flat_map(|rule| rule.check(scene, editor_scene))
+            .collect()
+    }
+}
+
+impl Default for ValidationRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}